@@ -1,9 +1,18 @@
 use crate::error::*;
 
+/// Whether an endpoint of a [`LineRange`] is part of the range itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    Inclusive,
+    Exclusive,
+}
+
 #[derive(Debug, Clone)]
 pub struct LineRange {
     lower: usize,
     upper: usize,
+    lower_bound: Bound,
+    upper_bound: Bound,
 }
 
 impl Default for LineRange {
@@ -11,6 +20,8 @@ impl Default for LineRange {
         LineRange {
             lower: usize::MIN,
             upper: usize::MAX,
+            lower_bound: Bound::Inclusive,
+            upper_bound: Bound::Inclusive,
         }
     }
 }
@@ -20,6 +31,17 @@ impl LineRange {
         LineRange {
             lower: from,
             upper: to,
+            lower_bound: Bound::Inclusive,
+            upper_bound: Bound::Inclusive,
+        }
+    }
+
+    pub fn new_with_bounds(from: usize, to: usize, lower_bound: Bound, upper_bound: Bound) -> Self {
+        LineRange {
+            lower: from,
+            upper: to,
+            lower_bound,
+            upper_bound,
         }
     }
 
@@ -28,6 +50,15 @@ impl LineRange {
     }
 
     fn parse_range(range_raw: &str) -> Result<LineRange> {
+        let first_byte = range_raw.bytes().next();
+        if first_byte == Some(b'[') || first_byte == Some(b'(') {
+            return LineRange::parse_bracket_range(range_raw);
+        }
+
+        if range_raw.contains("..") {
+            return LineRange::parse_rust_range(range_raw);
+        }
+
         let mut new_range = LineRange::default();
 
         if range_raw.bytes().next().ok_or("Empty line range")? == b':' {
@@ -78,8 +109,84 @@ impl LineRange {
         }
     }
 
+    /// Parses Rust's range-expression syntax: `N..M`, `N..=M`, `..M`, `..=M`, `N..`.
+    fn parse_rust_range(range_raw: &str) -> Result<LineRange> {
+        let (before, after, inclusive) = if let Some(rest) = range_raw.split_once("..=") {
+            (rest.0, rest.1, true)
+        } else if let Some(rest) = range_raw.split_once("..") {
+            (rest.0, rest.1, false)
+        } else {
+            return Err("Invalid range syntax".into());
+        };
+
+        // Reject things like "5..=..8" that contain a second ".." in either half.
+        if before.contains("..") || after.contains("..") {
+            return Err("Invalid range syntax".into());
+        }
+
+        let mut new_range = LineRange::default();
+
+        if !before.is_empty() {
+            new_range.lower = before.parse()?;
+        }
+
+        new_range.upper_bound = if inclusive || after.is_empty() {
+            // An open upper bound has no meaningful exclusive/inclusive distinction.
+            Bound::Inclusive
+        } else {
+            Bound::Exclusive
+        };
+
+        if !after.is_empty() {
+            new_range.upper = after.parse()?;
+        }
+
+        Ok(new_range)
+    }
+
+    /// Parses bracket notation such as `[3,8)` or `(3,8]`, where `[`/`]` denote an
+    /// inclusive endpoint and `(`/`)` denote an exclusive one.
+    fn parse_bracket_range(range_raw: &str) -> Result<LineRange> {
+        let bytes = range_raw.as_bytes();
+
+        let lower_bound = match bytes.first() {
+            Some(b'[') => Bound::Inclusive,
+            Some(b'(') => Bound::Exclusive,
+            _ => return Err("Invalid range syntax".into()),
+        };
+        let upper_bound = match bytes.last() {
+            Some(b']') => Bound::Inclusive,
+            Some(b')') => Bound::Exclusive,
+            _ => return Err("Invalid range syntax".into()),
+        };
+
+        let inner = &range_raw[1..range_raw.len() - 1];
+        let parts: Vec<&str> = inner.split(',').collect();
+        if parts.len() != 2 {
+            return Err(
+                "Invalid bracket range. Expected format: '[N,M)', '(N,M]', '[N,M]' or '(N,M)'"
+                    .into(),
+            );
+        }
+
+        let lower = parts[0].trim().parse()?;
+        let upper = parts[1].trim().parse()?;
+
+        Ok(LineRange::new_with_bounds(
+            lower,
+            upper,
+            lower_bound,
+            upper_bound,
+        ))
+    }
+
     pub(crate) fn is_inside(&self, line: usize) -> bool {
-        line >= self.lower && line <= self.upper
+        let above_lower =
+            line > self.lower || (self.lower_bound == Bound::Inclusive && line == self.lower);
+        let below_upper =
+            line < self.upper || (self.upper_bound == Bound::Inclusive && line == self.upper);
+
+        above_lower && below_upper
     }
 }
 
@@ -175,6 +282,92 @@ fn test_parse_minus_fail() {
     assert!(range.is_err());
 }
 
+#[test]
+fn test_parse_rust_range_exclusive() {
+    let range = LineRange::from("5..8").expect("Shouldn't fail on test!");
+    assert_eq!(5, range.lower);
+    assert_eq!(8, range.upper);
+    assert!(range.is_inside(5));
+    assert!(range.is_inside(7));
+    assert!(!range.is_inside(8));
+}
+
+#[test]
+fn test_parse_rust_range_inclusive() {
+    let range = LineRange::from("5..=8").expect("Shouldn't fail on test!");
+    assert_eq!(5, range.lower);
+    assert_eq!(8, range.upper);
+    assert!(range.is_inside(8));
+}
+
+#[test]
+fn test_parse_rust_range_open_lower() {
+    let range = LineRange::from("..3").expect("Shouldn't fail on test!");
+    assert_eq!(usize::MIN, range.lower);
+    assert_eq!(3, range.upper);
+    assert!(range.is_inside(2));
+    assert!(!range.is_inside(3));
+}
+
+#[test]
+fn test_parse_rust_range_open_upper() {
+    let range = LineRange::from("5..").expect("Shouldn't fail on test!");
+    assert_eq!(5, range.lower);
+    assert_eq!(usize::MAX, range.upper);
+    assert!(range.is_inside(usize::MAX));
+}
+
+#[test]
+fn test_parse_rust_range_malformed() {
+    let range = LineRange::from("5..=..8");
+    assert!(range.is_err());
+}
+
+#[test]
+fn test_parse_bracket_range_closed() {
+    let range = LineRange::from("[3,8]").expect("Shouldn't fail on test!");
+    assert!(!range.is_inside(2));
+    assert!(range.is_inside(3));
+    assert!(range.is_inside(8));
+    assert!(!range.is_inside(9));
+}
+
+#[test]
+fn test_parse_bracket_range_open() {
+    let range = LineRange::from("(3,8)").expect("Shouldn't fail on test!");
+    assert!(!range.is_inside(3));
+    assert!(range.is_inside(4));
+    assert!(range.is_inside(7));
+    assert!(!range.is_inside(8));
+}
+
+#[test]
+fn test_parse_bracket_range_half_open_lower() {
+    let range = LineRange::from("(3,8]").expect("Shouldn't fail on test!");
+    assert!(!range.is_inside(3));
+    assert!(range.is_inside(4));
+    assert!(range.is_inside(8));
+    assert!(!range.is_inside(9));
+}
+
+#[test]
+fn test_parse_bracket_range_half_open_upper() {
+    let range = LineRange::from("[3,8)").expect("Shouldn't fail on test!");
+    assert!(!range.is_inside(2));
+    assert!(range.is_inside(3));
+    assert!(range.is_inside(7));
+    assert!(!range.is_inside(8));
+}
+
+#[test]
+fn test_default_is_fully_closed() {
+    let range = LineRange::default();
+    assert_eq!(usize::MIN, range.lower);
+    assert_eq!(usize::MAX, range.upper);
+    assert!(range.is_inside(usize::MIN));
+    assert!(range.is_inside(usize::MAX));
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum RangeCheckResult {
     // Within one of the given ranges
@@ -203,22 +396,135 @@ impl LineRanges {
     }
 
     pub fn from(ranges: Vec<LineRange>) -> LineRanges {
-        let largest_upper_bound = ranges.iter().map(|r| r.upper).max().unwrap_or(usize::MAX);
+        let ranges = LineRanges::normalize(ranges);
+        let largest_upper_bound = ranges.last().map(|r| r.upper).unwrap_or(usize::MAX);
         LineRanges {
             ranges,
             largest_upper_bound,
         }
     }
 
+    /// Sorts `ranges` by their lower bound and merges any overlapping or
+    /// directly adjacent ranges, so that `check` can binary-search over a
+    /// disjoint list instead of scanning every range for every line.
+    fn normalize(ranges: Vec<LineRange>) -> Vec<LineRange> {
+        if ranges.is_empty() {
+            return ranges;
+        }
+
+        // Bound kinds only matter while merging; the merged result is always
+        // expressed as an equivalent closed (inclusive/inclusive) interval.
+        let mut closed: Vec<(usize, usize)> = ranges
+            .iter()
+            .map(|r| {
+                let lower = match r.lower_bound {
+                    Bound::Inclusive => r.lower,
+                    Bound::Exclusive => r.lower.saturating_add(1),
+                };
+                let upper = match r.upper_bound {
+                    Bound::Inclusive => r.upper,
+                    Bound::Exclusive => r.upper.saturating_sub(1),
+                };
+                (lower, upper)
+            })
+            .collect();
+
+        closed.sort_by_key(|&(lower, _)| lower);
+
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(closed.len());
+        for (lower, upper) in closed {
+            match merged.last_mut() {
+                Some((_, last_upper)) if lower <= last_upper.saturating_add(1) => {
+                    *last_upper = (*last_upper).max(upper);
+                }
+                _ => merged.push((lower, upper)),
+            }
+        }
+
+        merged
+            .into_iter()
+            .map(|(lower, upper)| LineRange::new(lower, upper))
+            .collect()
+    }
+
     pub(crate) fn check(&self, line: usize) -> RangeCheckResult {
-        if self.ranges.iter().any(|r| r.is_inside(line)) {
-            RangeCheckResult::InRange
-        } else if line < self.largest_upper_bound {
-            RangeCheckResult::BeforeOrBetweenRanges
-        } else {
-            RangeCheckResult::AfterLastRange
+        let idx = self.ranges.partition_point(|r| r.upper < line);
+
+        match self.ranges.get(idx) {
+            Some(r) if r.is_inside(line) => RangeCheckResult::InRange,
+            _ if line < self.largest_upper_bound => RangeCheckResult::BeforeOrBetweenRanges,
+            _ => RangeCheckResult::AfterLastRange,
         }
     }
+
+    /// Returns the lines covered by either `self` or `other`.
+    pub fn union(&self, other: &LineRanges) -> LineRanges {
+        let combined = self
+            .ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect();
+
+        LineRanges::from(combined)
+    }
+
+    /// Returns the lines covered by both `self` and `other`.
+    pub fn intersect(&self, other: &LineRanges) -> LineRanges {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let a = &self.ranges[i];
+            let b = &other.ranges[j];
+
+            let lower = a.lower.max(b.lower);
+            let upper = a.upper.min(b.upper);
+            if lower <= upper {
+                result.push(LineRange::new(lower, upper));
+            }
+
+            if a.upper < b.upper {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        LineRanges::from(result)
+    }
+
+    /// Returns the lines covered by `self` but not by `other`.
+    pub fn subtract(&self, other: &LineRanges) -> LineRanges {
+        let mut result = Vec::new();
+
+        for a in &self.ranges {
+            // `None` once the rest of `a` has been removed.
+            let mut lower = Some(a.lower);
+
+            for b in &other.ranges {
+                let start = match lower {
+                    Some(start) => start,
+                    None => break,
+                };
+                if b.upper < start || b.lower > a.upper {
+                    continue;
+                }
+
+                if b.lower > start {
+                    result.push(LineRange::new(start, b.lower - 1));
+                }
+
+                lower = b.upper.checked_add(1).filter(|&next| next <= a.upper);
+            }
+
+            if let Some(start) = lower {
+                result.push(LineRange::new(start, a.upper));
+            }
+        }
+
+        LineRanges::from(result)
+    }
 }
 
 impl Default for LineRanges {
@@ -296,3 +602,127 @@ fn test_ranges_none() {
 
     assert_ne!(RangeCheckResult::InRange, ranges.check(1));
 }
+
+#[test]
+fn test_ranges_merge_overlapping() {
+    let ranges = ranges(&["3:8", "5:10"]);
+
+    assert_eq!(1, ranges.ranges.len());
+    assert_eq!(3, ranges.ranges[0].lower);
+    assert_eq!(10, ranges.ranges[0].upper);
+}
+
+#[test]
+fn test_ranges_merge_adjacent() {
+    let ranges = ranges(&["3:8", "9:12"]);
+
+    assert_eq!(1, ranges.ranges.len());
+    assert_eq!(3, ranges.ranges[0].lower);
+    assert_eq!(12, ranges.ranges[0].upper);
+}
+
+#[test]
+fn test_ranges_merge_disjoint_stays_separate() {
+    let ranges = ranges(&["3:8", "11:20", "25:30"]);
+
+    assert_eq!(3, ranges.ranges.len());
+}
+
+#[test]
+fn test_ranges_merge_unsorted_input() {
+    let ranges = ranges(&["25:30", "3:8", "11:20"]);
+
+    assert_eq!(3, ranges.ranges.len());
+    assert_eq!(3, ranges.ranges[0].lower);
+    assert_eq!(11, ranges.ranges[1].lower);
+    assert_eq!(25, ranges.ranges[2].lower);
+}
+
+#[cfg(test)]
+fn bounds(rs: &LineRanges) -> Vec<(usize, usize)> {
+    rs.ranges.iter().map(|r| (r.lower, r.upper)).collect()
+}
+
+#[test]
+fn test_union_disjoint() {
+    let result = ranges(&["3:8"]).union(&ranges(&["20:30"]));
+    assert_eq!(vec![(3, 8), (20, 30)], bounds(&result));
+}
+
+#[test]
+fn test_union_touching() {
+    let result = ranges(&["3:8"]).union(&ranges(&["9:12"]));
+    assert_eq!(vec![(3, 12)], bounds(&result));
+}
+
+#[test]
+fn test_union_overlapping() {
+    let result = ranges(&["3:8"]).union(&ranges(&["5:12"]));
+    assert_eq!(vec![(3, 12)], bounds(&result));
+}
+
+#[test]
+fn test_union_nested() {
+    let result = ranges(&["3:20"]).union(&ranges(&["5:12"]));
+    assert_eq!(vec![(3, 20)], bounds(&result));
+}
+
+#[test]
+fn test_intersect_disjoint() {
+    let result = ranges(&["3:8"]).intersect(&ranges(&["20:30"]));
+    assert!(bounds(&result).is_empty());
+}
+
+#[test]
+fn test_intersect_touching() {
+    let result = ranges(&["3:8"]).intersect(&ranges(&["8:12"]));
+    assert_eq!(vec![(8, 8)], bounds(&result));
+}
+
+#[test]
+fn test_intersect_overlapping() {
+    let result = ranges(&["3:8"]).intersect(&ranges(&["5:12"]));
+    assert_eq!(vec![(5, 8)], bounds(&result));
+}
+
+#[test]
+fn test_intersect_nested() {
+    let result = ranges(&["3:20"]).intersect(&ranges(&["5:12"]));
+    assert_eq!(vec![(5, 12)], bounds(&result));
+}
+
+#[test]
+fn test_intersect_fully_overlapping() {
+    let result = ranges(&["3:20"]).intersect(&ranges(&["3:20"]));
+    assert_eq!(vec![(3, 20)], bounds(&result));
+}
+
+#[test]
+fn test_subtract_disjoint() {
+    let result = ranges(&["3:8"]).subtract(&ranges(&["20:30"]));
+    assert_eq!(vec![(3, 8)], bounds(&result));
+}
+
+#[test]
+fn test_subtract_touching() {
+    let result = ranges(&["3:8"]).subtract(&ranges(&["8:12"]));
+    assert_eq!(vec![(3, 7)], bounds(&result));
+}
+
+#[test]
+fn test_subtract_overlapping() {
+    let result = ranges(&["3:8"]).subtract(&ranges(&["5:12"]));
+    assert_eq!(vec![(3, 4)], bounds(&result));
+}
+
+#[test]
+fn test_subtract_nested() {
+    let result = ranges(&["3:20"]).subtract(&ranges(&["8:12"]));
+    assert_eq!(vec![(3, 7), (13, 20)], bounds(&result));
+}
+
+#[test]
+fn test_subtract_fully_overlapping() {
+    let result = ranges(&["3:20"]).subtract(&ranges(&["3:20"]));
+    assert!(bounds(&result).is_empty());
+}